@@ -0,0 +1,268 @@
+use async_stream::try_stream;
+use chrono::NaiveDate;
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+
+use crate::auth::{AuthError, GarminClient};
+
+/// Garmin's activity type filter, as accepted by `activityType` on the
+/// activities search endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityType {
+    Running,
+    Cycling,
+    Swimming,
+    Walking,
+    Hiking,
+    Strength,
+    Other,
+}
+
+impl ActivityType {
+    fn as_query_value(&self) -> &'static str {
+        match self {
+            ActivityType::Running => "running",
+            ActivityType::Cycling => "cycling",
+            ActivityType::Swimming => "swimming",
+            ActivityType::Walking => "walking",
+            ActivityType::Hiking => "hiking",
+            ActivityType::Strength => "strength_training",
+            ActivityType::Other => "other",
+        }
+    }
+}
+
+/// A single Garmin Connect activity, as returned by the activities search
+/// endpoint.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Activity {
+    #[serde(rename = "activityId")]
+    pub activity_id: i64,
+    #[serde(rename = "activityName")]
+    pub activity_name: String,
+    #[serde(rename = "startTimeLocal")]
+    pub start_time_local: String,
+    pub distance: Option<f64>,
+    pub duration: Option<f64>,
+}
+
+const DEFAULT_LIMIT: usize = 20;
+
+/// Fluent builder over `activities_url()`'s query parameters.
+///
+/// Build one via [`GarminClient::activities`], narrow it down with the
+/// `.start()`/`.limit()`/etc. setters, then either `.send()` a single page
+/// or `.items_iter()` to lazily walk every page.
+pub struct ActivitiesRequest<'a> {
+    client: &'a GarminClient,
+    start: usize,
+    limit: usize,
+    activity_type: Option<ActivityType>,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+    search: Option<String>,
+}
+
+impl<'a> ActivitiesRequest<'a> {
+    pub(crate) fn new(client: &'a GarminClient) -> Self {
+        Self {
+            client,
+            start: 0,
+            limit: DEFAULT_LIMIT,
+            activity_type: None,
+            start_date: None,
+            end_date: None,
+            search: None,
+        }
+    }
+
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn activity_type(mut self, activity_type: ActivityType) -> Self {
+        self.activity_type = Some(activity_type);
+        self
+    }
+
+    pub fn start_date(mut self, start_date: NaiveDate) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    pub fn end_date(mut self, end_date: NaiveDate) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    pub fn search(mut self, search: &str) -> Self {
+        self.search = Some(search.to_string());
+        self
+    }
+
+    fn query(&self) -> Vec<(String, String)> {
+        let mut query = vec![
+            ("start".to_string(), self.start.to_string()),
+            ("limit".to_string(), self.limit.to_string()),
+        ];
+        if let Some(activity_type) = self.activity_type {
+            query.push(("activityType".to_string(), activity_type.as_query_value().to_string()));
+        }
+        if let Some(start_date) = self.start_date {
+            query.push(("startDate".to_string(), start_date.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(end_date) = self.end_date {
+            query.push(("endDate".to_string(), end_date.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(search) = &self.search {
+            query.push(("search".to_string(), search.clone()));
+        }
+        query
+    }
+
+    /// Fetch a single page using the current `start`/`limit`.
+    pub async fn send(&self) -> Result<Vec<Activity>, AuthError> {
+        let access_token = self.client.access_token().await?;
+        let response = self
+            .client
+            .http
+            .get(self.client.url_builder.activities_url())
+            .bearer_auth(access_token)
+            .query(&self.query())
+            .send()
+            .await?;
+
+        response
+            .json::<Vec<Activity>>()
+            .await
+            .map_err(AuthError::from)
+    }
+
+    /// Lazily walk every page starting from `start`, bumping `start` by
+    /// `limit` after each request until a short page is returned.
+    pub fn items_iter(self) -> impl Stream<Item = Result<Activity, AuthError>> + 'a {
+        try_stream! {
+            let mut start = self.start;
+            let limit = self.limit;
+
+            loop {
+                let page = ActivitiesRequest { start, ..clone_request(&self) }.send().await?;
+                let page_len = page.len();
+
+                for activity in page {
+                    yield activity;
+                }
+
+                match next_start(start, limit, page_len) {
+                    Some(next) => start = next,
+                    None => break,
+                }
+            }
+        }
+    }
+}
+
+fn clone_request<'a>(request: &ActivitiesRequest<'a>) -> ActivitiesRequest<'a> {
+    ActivitiesRequest {
+        client: request.client,
+        start: request.start,
+        limit: request.limit,
+        activity_type: request.activity_type,
+        start_date: request.start_date,
+        end_date: request.end_date,
+        search: request.search.clone(),
+    }
+}
+
+/// Next `start` offset to request, or `None` once a short page signals
+/// there are no more results.
+fn next_start(start: usize, limit: usize, page_len: usize) -> Option<usize> {
+    if page_len < limit {
+        None
+    } else {
+        Some(start + limit)
+    }
+}
+
+impl GarminClient {
+    /// Start building an [`ActivitiesRequest`] against this client.
+    pub fn activities(&self) -> ActivitiesRequest<'_> {
+        ActivitiesRequest::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{OAuth1Token, OAuth2Token};
+    use crate::url_builder::UrlBuilder;
+    use chrono::{Duration, NaiveDate, Utc};
+
+    fn test_client() -> GarminClient {
+        GarminClient::from_parts(
+            UrlBuilder::default(),
+            reqwest::Client::new(),
+            OAuth1Token {
+                oauth_token: "token".to_string(),
+                oauth_token_secret: "secret".to_string(),
+            },
+            OAuth2Token {
+                access_token: "access".to_string(),
+                refresh_token: "refresh".to_string(),
+                expires_in: 3600,
+            },
+            Utc::now() + Duration::hours(1),
+        )
+    }
+
+    #[test]
+    fn test_query_defaults_to_start_and_limit_only() {
+        let client = test_client();
+        let request = ActivitiesRequest::new(&client);
+
+        assert_eq!(
+            request.query(),
+            vec![
+                ("start".to_string(), "0".to_string()),
+                ("limit".to_string(), "20".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_query_serializes_every_filter() {
+        let client = test_client();
+        let request = ActivitiesRequest::new(&client)
+            .start(40)
+            .limit(10)
+            .activity_type(ActivityType::Cycling)
+            .start_date(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+            .end_date(NaiveDate::from_ymd_opt(2026, 1, 31).unwrap())
+            .search("morning ride");
+
+        assert_eq!(
+            request.query(),
+            vec![
+                ("start".to_string(), "40".to_string()),
+                ("limit".to_string(), "10".to_string()),
+                ("activityType".to_string(), "cycling".to_string()),
+                ("startDate".to_string(), "2026-01-01".to_string()),
+                ("endDate".to_string(), "2026-01-31".to_string()),
+                ("search".to_string(), "morning ride".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_start_stops_on_a_short_page() {
+        assert_eq!(next_start(0, 50, 50), Some(50));
+        assert_eq!(next_start(50, 50, 12), None);
+        assert_eq!(next_start(0, 50, 0), None);
+    }
+}
@@ -0,0 +1,394 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use rand::Rng;
+use regex::Regex;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use tokio::sync::RwLock;
+
+use crate::url_builder::{GarminDomain, UrlBuilder};
+
+/// Garmin Connect's well-known OAuth1 consumer credentials, used to sign the
+/// ticket exchange in step 3 of the login flow.
+const CONSUMER_KEY: &str = "fc3e99d2-118c-44b8-8ae3-03370dde24c0";
+const CONSUMER_SECRET: &str = "E08WAR897mqmhF5XxfniUsOSfzRNZvDZhD1h";
+
+type HmacSha1 = Hmac<Sha1>;
+
+/// Errors that can occur while driving the Garmin SSO → OAuth2 login flow.
+#[derive(Debug)]
+pub enum AuthError {
+    Http(reqwest::Error),
+    MissingTicket,
+    Exchange(String),
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthError::Http(e) => write!(f, "request to Garmin failed: {}", e),
+            AuthError::MissingTicket => {
+                write!(f, "no service ticket found in the signin response")
+            }
+            AuthError::Exchange(msg) => write!(f, "oauth exchange failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+impl From<reqwest::Error> for AuthError {
+    fn from(e: reqwest::Error) -> Self {
+        AuthError::Http(e)
+    }
+}
+
+/// OAuth1 token/secret pair obtained by exchanging the SSO service ticket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuth1Token {
+    pub oauth_token: String,
+    pub oauth_token_secret: String,
+}
+
+/// OAuth2 bearer token obtained by exchanging the OAuth1 token.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuth2Token {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub expires_in: i64,
+}
+
+/// The part of a `GarminClient`'s session that's replaced on refresh.
+struct TokenState {
+    oauth2: OAuth2Token,
+    expires_at: DateTime<Utc>,
+}
+
+/// An authenticated Garmin Connect client.
+///
+/// Holds the OAuth1 and OAuth2 tokens minted by [`GarminClient::login`] and
+/// attaches `Authorization: Bearer` headers to subsequent calls against the
+/// `GcApi` endpoints. The OAuth2 token is checked before every call and
+/// transparently refreshed from the OAuth1 token if it has expired.
+pub struct GarminClient {
+    pub(crate) url_builder: UrlBuilder,
+    pub(crate) http: Client,
+    pub(crate) oauth1: OAuth1Token,
+    tokens: RwLock<TokenState>,
+}
+
+impl GarminClient {
+    /// Run the full OAuth1 → OAuth2 login flow for the given credentials.
+    pub async fn login(email: &str, password: &str) -> Result<Self, AuthError> {
+        Self::login_to_domain(email, password, None).await
+    }
+
+    /// Same as [`GarminClient::login`], but against a specific Garmin domain
+    /// (e.g. `GarminDomain::GarminCn`).
+    pub async fn login_to_domain(
+        email: &str,
+        password: &str,
+        domain: Option<GarminDomain>,
+    ) -> Result<Self, AuthError> {
+        let url_builder = UrlBuilder::new(domain);
+        let http = Client::builder().cookie_store(true).build()?;
+
+        // Step 1: seed the session with the embedded SSO page's cookies.
+        http.get(url_builder.garmin_sso_embed_url()).send().await?;
+
+        // Step 2: sign in and scrape the service ticket out of the response.
+        let ticket = signin(&http, &url_builder, email, password).await?;
+
+        // Step 3: exchange the ticket for an OAuth1 token/secret.
+        let oauth1 = exchange_ticket_for_oauth1(&http, &url_builder, &ticket).await?;
+
+        // Step 4: exchange the OAuth1 token for an OAuth2 bearer token.
+        let oauth2 = exchange_oauth1_for_oauth2(&http, &url_builder, &oauth1).await?;
+        let expires_at = expires_at_from_now(oauth2.expires_in);
+
+        Ok(Self {
+            url_builder,
+            http,
+            oauth1,
+            tokens: RwLock::new(TokenState { oauth2, expires_at }),
+        })
+    }
+
+    pub(crate) fn from_parts(
+        url_builder: UrlBuilder,
+        http: Client,
+        oauth1: OAuth1Token,
+        oauth2: OAuth2Token,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            url_builder,
+            http,
+            oauth1,
+            tokens: RwLock::new(TokenState { oauth2, expires_at }),
+        }
+    }
+
+    /// Re-run step 4 of the login flow against the stored OAuth1 token,
+    /// minting a fresh OAuth2 bearer token without re-authenticating.
+    pub(crate) async fn refresh_oauth2(&self) -> Result<(), AuthError> {
+        let oauth2 = exchange_oauth1_for_oauth2(&self.http, &self.url_builder, &self.oauth1).await?;
+        let expires_at = expires_at_from_now(oauth2.expires_in);
+        *self.tokens.write().await = TokenState { oauth2, expires_at };
+        Ok(())
+    }
+
+    /// The current OAuth2 bearer token, refreshing it first if it has
+    /// expired.
+    pub(crate) async fn access_token(&self) -> Result<String, AuthError> {
+        let expired = self.tokens.read().await.expires_at <= Utc::now();
+        if expired {
+            self.refresh_oauth2().await?;
+        }
+        Ok(self.tokens.read().await.oauth2.access_token.clone())
+    }
+
+    /// Issue an authenticated GET against a `GcApi` endpoint.
+    pub async fn get(&self, url: &str) -> Result<reqwest::Response, AuthError> {
+        let access_token = self.access_token().await?;
+        Ok(self.http.get(url).bearer_auth(access_token).send().await?)
+    }
+
+    pub fn url_builder(&self) -> &UrlBuilder {
+        &self.url_builder
+    }
+
+    pub(crate) fn oauth1(&self) -> &OAuth1Token {
+        &self.oauth1
+    }
+
+    pub(crate) async fn oauth2(&self) -> OAuth2Token {
+        self.tokens.read().await.oauth2.clone()
+    }
+
+    pub(crate) async fn expires_at(&self) -> DateTime<Utc> {
+        self.tokens.read().await.expires_at
+    }
+}
+
+fn expires_at_from_now(expires_in: i64) -> DateTime<Utc> {
+    Utc::now() + Duration::seconds(expires_in)
+}
+
+async fn signin(
+    http: &Client,
+    url_builder: &UrlBuilder,
+    email: &str,
+    password: &str,
+) -> Result<String, AuthError> {
+    let response = http
+        .post(url_builder.signin_url())
+        .query(&[
+            ("service", url_builder.garmin_sso_origin()),
+            ("webhost", url_builder.garmin_sso_origin()),
+            ("source", url_builder.signin_url().as_str()),
+            ("gauthHost", url_builder.gauth_host().as_str()),
+            ("embed", "true"),
+        ])
+        .form(&[("username", email), ("password", password), ("embed", "true")])
+        .send()
+        .await?;
+
+    let body = response.text().await?;
+    extract_ticket(&body).ok_or(AuthError::MissingTicket)
+}
+
+fn extract_ticket(html: &str) -> Option<String> {
+    let re = Regex::new(r#"ticket=([^"&]+)"#).expect("static regex is valid");
+    re.captures(html)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str().to_string())
+}
+
+async fn exchange_ticket_for_oauth1(
+    http: &Client,
+    url_builder: &UrlBuilder,
+    ticket: &str,
+) -> Result<OAuth1Token, AuthError> {
+    let url = format!("{}/preauthorized", url_builder.oauth_url());
+    let query = [("ticket", ticket), ("login-url", url_builder.signin_url().as_str())];
+    let authorization = oauth1_authorization_header("GET", &url, &query, None);
+
+    let response = http
+        .get(&url)
+        .query(&query)
+        .header("Authorization", authorization)
+        .send()
+        .await?;
+
+    let body = response.text().await?;
+    parse_oauth1_response(&body)
+}
+
+async fn exchange_oauth1_for_oauth2(
+    http: &Client,
+    url_builder: &UrlBuilder,
+    oauth1: &OAuth1Token,
+) -> Result<OAuth2Token, AuthError> {
+    let url = format!("{}/exchange/user/2.0", url_builder.oauth_url());
+    let authorization = oauth1_authorization_header("POST", &url, &[], Some(oauth1));
+
+    let response = http
+        .post(&url)
+        .header("Authorization", authorization)
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .send()
+        .await?;
+
+    response
+        .json::<OAuth2Token>()
+        .await
+        .map_err(|e| AuthError::Exchange(e.to_string()))
+}
+
+fn parse_oauth1_response(body: &str) -> Result<OAuth1Token, AuthError> {
+    let mut oauth_token = None;
+    let mut oauth_token_secret = None;
+
+    for pair in body.split('&') {
+        let mut parts = pair.splitn(2, '=');
+        match (parts.next(), parts.next()) {
+            (Some("oauth_token"), Some(v)) => oauth_token = Some(v.to_string()),
+            (Some("oauth_token_secret"), Some(v)) => oauth_token_secret = Some(v.to_string()),
+            _ => {}
+        }
+    }
+
+    match (oauth_token, oauth_token_secret) {
+        (Some(oauth_token), Some(oauth_token_secret)) => Ok(OAuth1Token {
+            oauth_token,
+            oauth_token_secret,
+        }),
+        _ => Err(AuthError::Exchange(
+            "response did not contain an oauth_token/oauth_token_secret pair".to_string(),
+        )),
+    }
+}
+
+/// Build an OAuth1 `Authorization` header, HMAC-SHA1 signed with Garmin's
+/// consumer key/secret and (once available) the caller's token/secret.
+fn oauth1_authorization_header(
+    method: &str,
+    url: &str,
+    extra_params: &[(&str, &str)],
+    token: Option<&OAuth1Token>,
+) -> String {
+    let nonce = oauth1_nonce();
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is after the epoch")
+        .as_secs();
+
+    let mut params: Vec<(String, String)> = vec![
+        ("oauth_consumer_key".to_string(), CONSUMER_KEY.to_string()),
+        ("oauth_nonce".to_string(), nonce.clone()),
+        ("oauth_signature_method".to_string(), "HMAC-SHA1".to_string()),
+        ("oauth_timestamp".to_string(), timestamp.to_string()),
+        ("oauth_version".to_string(), "1.0".to_string()),
+    ];
+    if let Some(token) = token {
+        params.push(("oauth_token".to_string(), token.oauth_token.clone()));
+    }
+    for (k, v) in extra_params {
+        params.push((k.to_string(), v.to_string()));
+    }
+    params.sort();
+
+    let param_string = params
+        .iter()
+        .map(|(k, v)| format!("{}={}", encode(k), encode(v)))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let base_string = format!("{}&{}&{}", method, encode(url), encode(&param_string));
+
+    let signing_key = format!(
+        "{}&{}",
+        encode(CONSUMER_SECRET),
+        token.map(|t| encode(&t.oauth_token_secret)).unwrap_or_default()
+    );
+
+    let mut mac = HmacSha1::new_from_slice(signing_key.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(base_string.as_bytes());
+    let signature = base64_encode(&mac.finalize().into_bytes());
+
+    let mut header_params = vec![
+        ("oauth_consumer_key".to_string(), CONSUMER_KEY.to_string()),
+        ("oauth_nonce".to_string(), nonce),
+        ("oauth_signature".to_string(), signature),
+        ("oauth_signature_method".to_string(), "HMAC-SHA1".to_string()),
+        ("oauth_timestamp".to_string(), timestamp.to_string()),
+        ("oauth_version".to_string(), "1.0".to_string()),
+    ];
+    if let Some(token) = token {
+        header_params.push(("oauth_token".to_string(), token.oauth_token.clone()));
+    }
+
+    let header_value = header_params
+        .iter()
+        .map(|(k, v)| format!(r#"{}="{}""#, k, encode(v)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!("OAuth {}", header_value)
+}
+
+fn oauth1_nonce() -> String {
+    let mut rng = rand::thread_rng();
+    (0..32)
+        .map(|_| rng.sample(rand::distributions::Alphanumeric) as char)
+        .collect()
+}
+
+fn encode(s: &str) -> String {
+    percent_encoding::utf8_percent_encode(s, percent_encoding::NON_ALPHANUMERIC).to_string()
+}
+
+fn base64_encode(bytes: &[u8]) -> String {
+    use base64::Engine;
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_ticket_from_signin_response() {
+        let html = r#"<script>var response_url = 'https://connect.garmin.com/modern?ticket=ST-0123456-abcDEF';</script>"#;
+        assert_eq!(
+            extract_ticket(html),
+            Some("ST-0123456-abcDEF".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_ticket_missing_returns_none() {
+        let html = "<html><body>invalid credentials</body></html>";
+        assert_eq!(extract_ticket(html), None);
+    }
+
+    #[test]
+    fn test_parse_oauth1_response_happy_path() {
+        let body = "oauth_token=abc123&oauth_token_secret=shh&oauth_callback_confirmed=true";
+        let token = parse_oauth1_response(body).unwrap();
+        assert_eq!(token.oauth_token, "abc123");
+        assert_eq!(token.oauth_token_secret, "shh");
+    }
+
+    #[test]
+    fn test_parse_oauth1_response_missing_pair_is_an_error() {
+        let body = "oauth_token=abc123&oauth_callback_confirmed=true";
+        assert!(parse_oauth1_response(body).is_err());
+    }
+}
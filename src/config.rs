@@ -0,0 +1,192 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::url_builder::GarminDomain;
+
+/// Env var pointing at the YAML/TOML config file to load at startup.
+const CONFIG_PATH_ENV: &str = "PULSE_CONFIG_PATH";
+
+/// Top-level application config, loaded from the file at `$PULSE_CONFIG_PATH`.
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub garmin: GarminConfig,
+    #[serde(default)]
+    pub server: ServerConfig,
+    #[serde(default)]
+    pub database: DatabaseConfig,
+    pub webhook_secret: String,
+}
+
+/// Garmin-related settings: which domain to authenticate against, and either
+/// credentials for a fresh login or a path to a cached token file.
+#[derive(Debug, Default, Deserialize)]
+pub struct GarminConfig {
+    pub domain: Option<String>,
+    pub email: Option<String>,
+    pub password: Option<String>,
+    pub token_cache_path: Option<PathBuf>,
+}
+
+/// HTTP server settings for the axum webhook listener.
+#[derive(Debug, Deserialize)]
+pub struct ServerConfig {
+    pub bind_address: String,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind_address: "127.0.0.1:8080".to_string(),
+        }
+    }
+}
+
+/// SQLite storage settings for persisted mood entries.
+#[derive(Debug, Deserialize)]
+pub struct DatabaseConfig {
+    pub path: PathBuf,
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            path: PathBuf::from("pulse.db"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    MissingPathEnv,
+    Io(std::io::Error),
+    Parse(String),
+    InvalidBindAddress(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::MissingPathEnv => {
+                write!(f, "{} is not set", CONFIG_PATH_ENV)
+            }
+            ConfigError::Io(e) => write!(f, "failed to read config file: {}", e),
+            ConfigError::Parse(msg) => write!(f, "failed to parse config file: {}", msg),
+            ConfigError::InvalidBindAddress(addr) => {
+                write!(f, "invalid server.bind_address: {}", addr)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(e: std::io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+impl Config {
+    /// Load the config file pointed at by `$PULSE_CONFIG_PATH`.
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = env::var(CONFIG_PATH_ENV).map_err(|_| ConfigError::MissingPathEnv)?;
+        Self::load_from(&path)
+    }
+
+    /// Load and parse a specific config file, dispatching on its extension.
+    pub fn load_from(path: &str) -> Result<Self, ConfigError> {
+        let data = fs::read_to_string(path)?;
+
+        if path.ends_with(".toml") {
+            toml::from_str(&data).map_err(|e| ConfigError::Parse(e.to_string()))
+        } else {
+            serde_yaml::from_str(&data).map_err(|e| ConfigError::Parse(e.to_string()))
+        }
+    }
+
+    pub fn bind_address(&self) -> Result<SocketAddr, ConfigError> {
+        self.server
+            .bind_address
+            .parse()
+            .map_err(|_| ConfigError::InvalidBindAddress(self.server.bind_address.clone()))
+    }
+
+    /// The Garmin domain configured for this deployment, if any.
+    pub fn garmin_domain(&self) -> Option<GarminDomain> {
+        self.garmin.domain.as_deref().map(GarminDomain::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp_config(file_name: &str, contents: &str) -> PathBuf {
+        let path = env::temp_dir().join(file_name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_load_from_yaml() {
+        let path = write_temp_config(
+            "pulse_config_test.yaml",
+            "webhook_secret: shh\nserver:\n  bind_address: 0.0.0.0:9000\n",
+        );
+
+        let config = Config::load_from(path.to_str().unwrap()).unwrap();
+        assert_eq!(config.webhook_secret, "shh");
+        assert_eq!(config.server.bind_address, "0.0.0.0:9000");
+    }
+
+    #[test]
+    fn test_load_from_toml() {
+        let path = write_temp_config(
+            "pulse_config_test.toml",
+            "webhook_secret = \"shh\"\n\n[server]\nbind_address = \"0.0.0.0:9000\"\n",
+        );
+
+        let config = Config::load_from(path.to_str().unwrap()).unwrap();
+        assert_eq!(config.webhook_secret, "shh");
+        assert_eq!(config.server.bind_address, "0.0.0.0:9000");
+    }
+
+    #[test]
+    fn test_load_from_missing_file_is_an_error() {
+        assert!(Config::load_from("/nonexistent/pulse-config-does-not-exist.yaml").is_err());
+    }
+
+    #[test]
+    fn test_bind_address_parses_valid_address() {
+        let config = Config {
+            garmin: GarminConfig::default(),
+            server: ServerConfig {
+                bind_address: "127.0.0.1:9090".to_string(),
+            },
+            database: DatabaseConfig::default(),
+            webhook_secret: "shh".to_string(),
+        };
+
+        assert_eq!(config.bind_address().unwrap().to_string(), "127.0.0.1:9090");
+    }
+
+    #[test]
+    fn test_bind_address_rejects_invalid_address() {
+        let config = Config {
+            garmin: GarminConfig::default(),
+            server: ServerConfig {
+                bind_address: "not-an-address".to_string(),
+            },
+            database: DatabaseConfig::default(),
+            webhook_secret: "shh".to_string(),
+        };
+
+        assert!(config.bind_address().is_err());
+    }
+}
@@ -1,24 +1,52 @@
+mod activities;
+mod auth;
+mod config;
+mod store;
+mod token_store;
+mod url_builder;
+
+use std::collections::HashSet;
 use std::error::Error;
 use std::io::Cursor;
+use std::sync::Arc;
 use chrono::{Days, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
 use axum::{
-    body::Bytes, 
-    http::HeaderMap, 
-    routing::post, 
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Json},
+    routing::post,
     Router,
 };
 
+use config::Config;
+use store::Store;
+use url_builder::UrlBuilder;
+
+struct AppState {
+    config: Config,
+    store: Store,
+    #[allow(dead_code)]
+    url_builder: UrlBuilder,
+}
+
+#[derive(Debug, Serialize)]
+struct IngestResponse {
+    inserted: usize,
+    missing_entry_dates: Vec<NaiveDate>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MoodEntry {
-    full_date: String,
-    date: String,
-    weekday: String,
-    time: String,
-    mood: String,
-    activities: String,
-    note_title: String,
-    note: String,
+    pub(crate) full_date: String,
+    pub(crate) date: String,
+    pub(crate) weekday: String,
+    pub(crate) time: String,
+    pub(crate) mood: String,
+    pub(crate) activities: String,
+    pub(crate) note_title: String,
+    pub(crate) note: String,
 }
 
 pub fn parse_csv_string(csv_data: &str) -> Result<Vec<MoodEntry>, Box<dyn Error>> {
@@ -38,65 +66,143 @@ pub fn parse_csv_string(csv_data: &str) -> Result<Vec<MoodEntry>, Box<dyn Error>
 
 #[tokio::main]
 async fn main() {
-    let app = Router::new().route("/log", post(handler));
-    let listener = tokio::net::TcpListener::bind("127.0.0.1:8080")
-        .await
-        .unwrap();
+    let config = Config::load().expect("failed to load config from $PULSE_CONFIG_PATH");
+    let bind_address = config.bind_address().expect("invalid server.bind_address");
+    let url_builder = UrlBuilder::new(config.garmin_domain());
+    let store = Store::open(&config.database.path).expect("failed to open mood entry store");
+
+    let state = Arc::new(AppState {
+        config,
+        store,
+        url_builder,
+    });
+    let app = Router::new().route("/log", post(handler)).with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(bind_address).await.unwrap();
     println!("listening on {}", listener.local_addr().unwrap());
     axum::serve(listener, app).await.unwrap();
 }
 
 async fn handler(
+    State(state): State<Arc<AppState>>,
     headers: HeaderMap,
     body: Bytes,
-)  {
-    let authenticate = headers.get("x-authenticate");
-    if authenticate.is_none() || authenticate.unwrap().to_str().unwrap() != "daylio" {
-        println!("unauthorized");
-        return
+) -> impl IntoResponse {
+    let authenticate = headers.get("x-authenticate").and_then(|v| v.to_str().ok());
+    if authenticate != Some(state.config.webhook_secret.as_str()) {
+        return StatusCode::UNAUTHORIZED.into_response();
     }
-    
+
     let body_str = match String::from_utf8(body.to_vec()) {
         Ok(str) => str,
-        Err(_) => 
-        {
-            println!("Invalid UTF-8 data");
-            return
-        }
+        Err(_) => return StatusCode::BAD_REQUEST.into_response(),
     };
-    
+
     let entries = match parse_csv_string(&body_str) {
         Ok(entries) => entries,
-        Err(_) =>{
-            println!("Invalid CSV data");
-            return
-        }
+        Err(_) => return StatusCode::BAD_REQUEST.into_response(),
     };
 
-    let missing_entries = get_missing_entry_dates(&entries);
+    let missing_entry_dates = match get_missing_entry_dates(&entries) {
+        Ok(dates) => dates,
+        Err(_) => return StatusCode::BAD_REQUEST.into_response(),
+    };
 
-    println!("{:?}", missing_entries);
+    let inserted = match state.store.upsert_entries(&entries) {
+        Ok(inserted) => inserted,
+        Err(_) => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+
+    Json(IngestResponse {
+        inserted,
+        missing_entry_dates,
+    })
+    .into_response()
 }
 
-fn get_missing_entry_dates(entries: &Vec<MoodEntry>) -> Vec<NaiveDate> {
-    let today = Local::now().date_naive();
-    let latest_entry = NaiveDate::parse_from_str(&entries[0].full_date, "%Y-%m-%d").unwrap();
-    let outstanding_entries = (today - latest_entry).num_days();
+/// Dates strictly between the latest logged entry and today that have no
+/// corresponding entry in `entries`, regardless of the order entries arrive in.
+fn get_missing_entry_dates(entries: &[MoodEntry]) -> Result<Vec<NaiveDate>, Box<dyn Error>> {
+    if entries.is_empty() {
+        return Ok(Vec::new());
+    }
 
-    if outstanding_entries == 0 {
-        return Vec::new();
+    let mut logged_dates = HashSet::new();
+    let mut latest_entry = None;
+
+    for entry in entries {
+        let date = NaiveDate::parse_from_str(&entry.full_date, "%Y-%m-%d")?;
+        match latest_entry {
+            Some(latest) if latest >= date => {}
+            _ => latest_entry = Some(date),
+        }
+        logged_dates.insert(date);
     }
+    let latest_entry = latest_entry.expect("entries is non-empty");
 
+    let today = Local::now().date_naive();
     let mut missing_entries = Vec::new();
-    for i in 0..outstanding_entries {
-        let date = today.checked_sub_days(Days::new(i as u64));
-        if date.is_some() {
-            missing_entries.push(date.unwrap());
-        } else {
-            println!("Error: date is None");
-            return Vec::new();
+    let mut date = latest_entry.succ_opt();
+
+    while let Some(d) = date {
+        if d >= today {
+            break;
+        }
+        if !logged_dates.contains(&d) {
+            missing_entries.push(d);
+        }
+        date = d.succ_opt();
+    }
+
+    Ok(missing_entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(full_date: &str) -> MoodEntry {
+        MoodEntry {
+            full_date: full_date.to_string(),
+            date: String::new(),
+            weekday: String::new(),
+            time: "08:00".to_string(),
+            mood: "rad".to_string(),
+            activities: String::new(),
+            note_title: String::new(),
+            note: String::new(),
         }
     }
 
-    missing_entries
+    #[test]
+    fn test_empty_input_returns_no_missing_dates() {
+        let entries: Vec<MoodEntry> = Vec::new();
+        assert_eq!(get_missing_entry_dates(&entries).unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn test_latest_entry_today_returns_no_missing_dates() {
+        let today = Local::now().date_naive();
+        let entries = vec![entry(&today.format("%Y-%m-%d").to_string())];
+        assert_eq!(get_missing_entry_dates(&entries).unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn test_multi_day_gap_with_out_of_order_input() {
+        let today = Local::now().date_naive();
+        let latest = today - Days::new(4);
+        let already_logged = today - Days::new(2);
+
+        // Deliberately out of order, and entry 0 is not the most recent.
+        let entries = vec![
+            entry(&already_logged.format("%Y-%m-%d").to_string()),
+            entry(&latest.format("%Y-%m-%d").to_string()),
+        ];
+
+        let expected = vec![
+            latest + Days::new(1),
+            latest + Days::new(3),
+        ];
+        assert_eq!(get_missing_entry_dates(&entries).unwrap(), expected);
+    }
 }
\ No newline at end of file
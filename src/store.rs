@@ -0,0 +1,157 @@
+use std::fmt;
+use std::path::Path;
+use std::sync::Mutex;
+
+use rusqlite::{params, Connection};
+
+use crate::MoodEntry;
+
+#[derive(Debug)]
+pub enum StoreError {
+    Sqlite(rusqlite::Error),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::Sqlite(e) => write!(f, "sqlite error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+impl From<rusqlite::Error> for StoreError {
+    fn from(e: rusqlite::Error) -> Self {
+        StoreError::Sqlite(e)
+    }
+}
+
+/// SQLite-backed persistence for `MoodEntry` rows, keyed on `(full_date, time)`.
+pub struct Store {
+    conn: Mutex<Connection>,
+}
+
+impl Store {
+    /// Open (or create) the database at `path` and ensure its schema exists.
+    pub fn open(path: &Path) -> Result<Self, StoreError> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS mood_entries (
+                full_date TEXT NOT NULL,
+                date TEXT NOT NULL,
+                weekday TEXT NOT NULL,
+                time TEXT NOT NULL,
+                mood TEXT NOT NULL,
+                activities TEXT NOT NULL,
+                note_title TEXT NOT NULL,
+                note TEXT NOT NULL,
+                PRIMARY KEY (full_date, time)
+            )",
+        )?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// Upsert each entry keyed on `(full_date, time)`. Returns the number of
+    /// rows written.
+    pub fn upsert_entries(&self, entries: &[MoodEntry]) -> Result<usize, StoreError> {
+        let conn = self.conn.lock().expect("mood_entries connection poisoned");
+
+        for entry in entries {
+            conn.execute(
+                "INSERT INTO mood_entries
+                    (full_date, date, weekday, time, mood, activities, note_title, note)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                 ON CONFLICT(full_date, time) DO UPDATE SET
+                    date = excluded.date,
+                    weekday = excluded.weekday,
+                    mood = excluded.mood,
+                    activities = excluded.activities,
+                    note_title = excluded.note_title,
+                    note = excluded.note",
+                params![
+                    entry.full_date,
+                    entry.date,
+                    entry.weekday,
+                    entry.time,
+                    entry.mood,
+                    entry.activities,
+                    entry.note_title,
+                    entry.note,
+                ],
+            )?;
+        }
+
+        Ok(entries.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(full_date: &str, time: &str, mood: &str) -> MoodEntry {
+        MoodEntry {
+            full_date: full_date.to_string(),
+            date: full_date.to_string(),
+            weekday: "Mon".to_string(),
+            time: time.to_string(),
+            mood: mood.to_string(),
+            activities: String::new(),
+            note_title: String::new(),
+            note: String::new(),
+        }
+    }
+
+    fn mood_for(store: &Store, full_date: &str, time: &str) -> String {
+        store
+            .conn
+            .lock()
+            .unwrap()
+            .query_row(
+                "SELECT mood FROM mood_entries WHERE full_date = ?1 AND time = ?2",
+                params![full_date, time],
+                |row| row.get(0),
+            )
+            .unwrap()
+    }
+
+    fn row_count(store: &Store) -> i64 {
+        store
+            .conn
+            .lock()
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM mood_entries", [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_upsert_inserts_new_rows() {
+        let store = Store::open(Path::new(":memory:")).unwrap();
+
+        let inserted = store
+            .upsert_entries(&[entry("2026-01-01", "08:00", "rad")])
+            .unwrap();
+
+        assert_eq!(inserted, 1);
+        assert_eq!(row_count(&store), 1);
+        assert_eq!(mood_for(&store, "2026-01-01", "08:00"), "rad");
+    }
+
+    #[test]
+    fn test_upsert_updates_on_conflicting_key() {
+        let store = Store::open(Path::new(":memory:")).unwrap();
+
+        store
+            .upsert_entries(&[entry("2026-01-01", "08:00", "rad")])
+            .unwrap();
+        store
+            .upsert_entries(&[entry("2026-01-01", "08:00", "awful")])
+            .unwrap();
+
+        assert_eq!(row_count(&store), 1);
+        assert_eq!(mood_for(&store, "2026-01-01", "08:00"), "awful");
+    }
+}
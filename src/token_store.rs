@@ -0,0 +1,130 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::auth::{AuthError, GarminClient, OAuth1Token, OAuth2Token};
+use crate::url_builder::{GarminDomain, UrlBuilder};
+
+/// On-disk representation of a cached Garmin session.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedTokens {
+    domain: GarminDomainRepr,
+    oauth1: OAuth1Token,
+    oauth2: OAuth2Token,
+    expires_at: DateTime<Utc>,
+}
+
+/// `GarminDomain` isn't `Serialize`/`Deserialize` itself; round-trip it
+/// through its string form instead.
+#[derive(Debug, Serialize, Deserialize)]
+struct GarminDomainRepr(String);
+
+impl From<&GarminDomain> for GarminDomainRepr {
+    fn from(domain: &GarminDomain) -> Self {
+        GarminDomainRepr(domain.to_string())
+    }
+}
+
+impl From<GarminDomainRepr> for GarminDomain {
+    fn from(repr: GarminDomainRepr) -> Self {
+        GarminDomain::from(repr.0.as_str())
+    }
+}
+
+#[derive(Debug)]
+pub enum TokenStoreError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Auth(AuthError),
+}
+
+impl fmt::Display for TokenStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenStoreError::Io(e) => write!(f, "token cache io error: {}", e),
+            TokenStoreError::Json(e) => write!(f, "token cache is not valid JSON: {}", e),
+            TokenStoreError::Auth(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for TokenStoreError {}
+
+impl From<std::io::Error> for TokenStoreError {
+    fn from(e: std::io::Error) -> Self {
+        TokenStoreError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for TokenStoreError {
+    fn from(e: serde_json::Error) -> Self {
+        TokenStoreError::Json(e)
+    }
+}
+
+impl From<AuthError> for TokenStoreError {
+    fn from(e: AuthError) -> Self {
+        TokenStoreError::Auth(e)
+    }
+}
+
+/// Default location for the cached token file: `<OS config dir>/pulse/garmin_tokens.json`.
+pub fn default_token_path() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("pulse")
+        .join("garmin_tokens.json")
+}
+
+impl GarminClient {
+    /// Load a previously cached session from `path`. If the OAuth2 token has
+    /// expired but the OAuth1 token is still valid, silently re-runs the
+    /// step-4 exchange and rewrites the cache with the refreshed token.
+    pub async fn from_cached(path: &Path) -> Result<Self, TokenStoreError> {
+        let data = fs::read_to_string(path)?;
+        let cached: CachedTokens = serde_json::from_str(&data)?;
+
+        let url_builder = UrlBuilder::new(Some(cached.domain.into()));
+        let http = Client::builder()
+            .cookie_store(true)
+            .build()
+            .map_err(AuthError::from)?;
+
+        let client = GarminClient::from_parts(
+            url_builder,
+            http,
+            cached.oauth1,
+            cached.oauth2,
+            cached.expires_at,
+        );
+
+        if client.expires_at().await <= Utc::now() {
+            client.refresh_oauth2().await?;
+            client.save_tokens(path).await?;
+        }
+
+        Ok(client)
+    }
+
+    /// Serialize this client's tokens to `path`, creating parent directories
+    /// as needed.
+    pub async fn save_tokens(&self, path: &Path) -> Result<(), TokenStoreError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let cached = CachedTokens {
+            domain: self.url_builder().domain().into(),
+            oauth1: self.oauth1().clone(),
+            oauth2: self.oauth2().await,
+            expires_at: self.expires_at().await,
+        };
+
+        fs::write(path, serde_json::to_string_pretty(&cached)?)?;
+        Ok(())
+    }
+}
@@ -34,6 +34,26 @@ impl ToString for GarminDomain {
     }
 }
 
+impl GarminDomain {
+    /// Host behind the `sso.` subdomain.
+    fn sso_host(&self) -> String {
+        match self {
+            GarminDomain::GarminCn => "sso.garmin.cn".to_string(),
+            _ => format!("sso.{}", self.to_string()),
+        }
+    }
+
+    /// Value for the `gauthHost` query parameter sent during signin. The CN
+    /// tier's gateway expects the embed path appended; every other domain
+    /// just wants the bare SSO origin.
+    fn gauth_host(&self) -> String {
+        match self {
+            GarminDomain::GarminCn => format!("https://{}/sso/embed", self.sso_host()),
+            _ => format!("https://{}/sso", self.sso_host()),
+        }
+    }
+}
+
 /// Type alias for workout ID
 pub type GCWorkoutId = String;
 
@@ -66,7 +86,7 @@ impl UrlBuilder {
         let domain_str = domain.to_string();
 
         let gc_modern = format!("https://connect.{}/modern", domain_str);
-        let garmin_sso_origin = format!("https://sso.{}", domain_str);
+        let garmin_sso_origin = format!("https://{}", domain.sso_host());
         let gc_api = format!("https://connectapi.{}", domain_str);
 
         Self {
@@ -278,6 +298,12 @@ impl UrlBuilder {
     pub fn gc_api(&self) -> &str {
         &self.gc_api
     }
+
+    /// Get the `gauthHost` value to send when signing in, which differs for
+    /// the CN tier.
+    pub fn gauth_host(&self) -> String {
+        self.domain.gauth_host()
+    }
 }
 
 impl Default for UrlBuilder {
@@ -464,6 +490,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cn_gauth_host_differs_from_com() {
+        let com_builder = UrlBuilder::default();
+        let cn_builder = UrlBuilder::new(Some(GarminDomain::GarminCn));
+
+        // CN's SSO gateway expects an `/embed` suffix on gauthHost that
+        // .com's signin params don't use.
+        assert_eq!(com_builder.gauth_host(), "https://sso.garmin.com/sso");
+        assert_eq!(cn_builder.gauth_host(), "https://sso.garmin.cn/sso/embed");
+        assert_eq!(cn_builder.garmin_sso_origin(), "https://sso.garmin.cn");
+        assert_eq!(cn_builder.gc_api(), "https://connectapi.garmin.cn");
+    }
+
     #[test]
     fn test_url_base_enum() {
         let builder = UrlBuilder::default();